@@ -1,4 +1,5 @@
-use rppal::gpio::{Gpio, Mode};
+use rppal::gpio::{Gpio, Mode, OutputPin};
+use rppal::spi::{Bus, Mode as SpiMode, SlaveSelect, Spi};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
@@ -9,16 +10,52 @@ use axum::{
     Json, Router,
 };
 use serde::Serialize;
-use rosc::{OscMessage, OscPacket, OscType, encoder};
+use rosc::{OscMessage, OscPacket, OscType, decoder, encoder};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::sync::broadcast;
+use tokio_stream::{Stream, StreamExt};
+use tokio_stream::wrappers::BroadcastStream;
+use axum::response::sse::{Event, Sse};
+use std::convert::Infallible;
 
 const PIN_A: u8 = 18;
 const PIN_B: u8 = 24;
 
+// Charge-time timeout, in busy-loop iterations, before we give up and treat
+// the reading as an open circuit
+const CHARGE_MAX_COUNT: u32 = 1_000_000;
+
 // OSC Configuration
 const OSC_ADDRESS: &str = "/volume/fader/1";
 const OSC_TARGET: &str = "192.168.1.100:9000";  // Change to your target device
 const OSC_ENABLED: bool = true;
 
+// Analog output configuration - mirrors `actual` to a real control voltage
+// for analog gear (VCAs, outboard preamps) via an AD5680-style 18-bit
+// serial DAC, written in lockstep with the OSC send
+const DAC_ENABLED: bool = false;
+const DAC_SPI_BUS: Bus = Bus::Spi0;
+const DAC_SPI_SLAVE_SELECT: SlaveSelect = SlaveSelect::Ss0;
+const DAC_SPI_CLOCK_HZ: u32 = 1_000_000;
+const DAC_SYNC_PIN: u8 = 25;  // AD5680 SYNC (frame sync / chip select)
+const DAC_MAX_VALUE: u32 = (1 << 18) - 1;  // 18-bit DAC full scale
+
+// Motorized-fader position servo - drives an H-bridge to physically move the
+// fader to a setpoint received over OSC or the TCP control interface
+const FADER_SERVO_ENABLED: bool = false;
+const OSC_LISTEN_ADDR: &str = "0.0.0.0:9001";  // incoming OSC sets the fader setpoint
+const MOTOR_PIN_FWD: u8 = 23;
+const MOTOR_PIN_REV: u8 = 22;
+const MOTOR_PWM_FREQUENCY_HZ: f64 = 1000.0;
+
+const PID_KP: f32 = 2.0;
+const PID_KI: f32 = 0.5;
+const PID_KD: f32 = 0.05;
+const PID_INTEGRAL_CLAMP: f32 = 1.0;  // anti-windup
+const PID_OUTPUT_CLAMP: f32 = 1.0;    // max PWM duty magnitude
+const PID_DEADBAND: f32 = 0.01;       // don't drive the motor this close to the setpoint
+
 // Calibration values for normalizing potentiometer reading to 0.0-1.0
 const POT_MIN: u32 = 0;
 const POT_MAX: u32 = 100000;  // Adjust based on your actual readings
@@ -40,12 +77,97 @@ enum VolumeCurve {
 
 const VOLUME_CURVE: VolumeCurve = VolumeCurve::Logarithmic;
 
+// Input filter configuration
+// A single charge_time() sample is noisy and jittery (the busy-loop count
+// gets preempted by the OS scheduler), which is why rate limiting exists
+// downstream. Oversample N raw readings per report and combine them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FilterMode {
+    Mean,   // Average of the N samples
+    Median, // Robust to spikes from scheduler preemption
+    Iir,    // Single-pole IIR: y += alpha * (x - y)
+}
+
+const FILTER_MODE: FilterMode = FilterMode::Mean;
+const FILTER_OVERSAMPLE_N: usize = 8;
+const FILTER_ALPHA: f32 = 0.2;
+// `Filter::sample` runs on a blocking-pool thread (see the reader task), so
+// a large count no longer stalls the async runtime - this bound just keeps
+// a single report cycle from taking an unreasonable amount of wall-clock time.
+const MIN_FILTER_OVERSAMPLE_N: usize = 1;
+const MAX_FILTER_OVERSAMPLE_N: usize = 256;
+
+// Fault detection configuration
+// A disconnected or shorted pot shows up in the raw charge-time counts
+// before it ever reaches the volume curve: an open circuit pegs the count
+// at CHARGE_MAX_COUNT, a short collapses it near zero, and a flaky wire
+// shows up as high variance. Tune these thresholds for your hardware.
+const FAULT_WINDOW_SIZE: usize = 10;
+const FAULT_OPEN_THRESHOLD: u32 = 950_000;     // mean count at/above this => open circuit
+const FAULT_SHORT_THRESHOLD: u32 = 50;         // mean count at/below this => shorted
+const FAULT_VARIANCE_THRESHOLD: f64 = 4.0e8;   // variance at/above this => intermittent wire
+
 // dB range for logarithmic curve
 // Minimum dB when pot is at 0 (typically -60 to -90)
 // Maximum dB when pot is at 1.0 (typically 0 to +10)
 const DB_MIN: f32 = -60.0;  // Full attenuation
 const DB_MAX: f32 = 0.0;    // Unity gain (0 dB)
 
+// TCP control server, line-oriented commands in, line-delimited JSON out.
+// Lets an operator retune the fader live without recompiling for the Pi.
+const TCP_CONTROL_ADDR: &str = "0.0.0.0:3001";
+
+// Default period between potentiometer samples (1s, the original poll
+// rate). Runtime-tunable via the `interval` command; set it low (10-100ms)
+// for smooth fader motion.
+const SAMPLE_INTERVAL_MS: u64 = 1000;
+const MIN_SAMPLE_INTERVAL_MS: u64 = 10;
+const MAX_SAMPLE_INTERVAL_MS: u64 = 60_000;
+
+// Capacity of the sample broadcast channel feeding `/stream` and TCP
+// streaming connections. Slow subscribers that fall this far behind just
+// skip ahead rather than blocking the reader loop.
+const REPORT_CHANNEL_CAPACITY: usize = 64;
+
+/// Runtime-tunable settings, shared between the reader loop and the TCP
+/// control server via `Arc<Mutex<Config>>`. Everything here started life as
+/// a `const` above; the consts still provide the startup defaults.
+#[derive(Debug, Clone)]
+struct Config {
+    osc_target: SocketAddr,
+    volume_curve: VolumeCurve,
+    pot_min: u32,
+    pot_max: u32,
+    max_rate_up: f32,
+    max_rate_down: f32,
+    sample_interval_ms: u64,
+    filter_mode: FilterMode,
+    filter_oversample_n: usize,
+    filter_alpha: f32,
+    fader_setpoint: f32,
+    pid_kp: f32,
+    pid_ki: f32,
+    pid_kd: f32,
+    pid_deadband: f32,
+}
+
+/// Most recent processed reading, used to answer the TCP `report` command
+/// and as the payload pushed to `/stream` and streaming TCP connections.
+#[derive(Debug, Clone, Serialize, Default)]
+struct Report {
+    raw: u32,
+    linear: f32,
+    target: f32,
+    actual: f32,
+    db: f32,
+    timestamp: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fault: Option<Fault>,
+    setpoint: f32,
+    measured: f32,
+    duty: f32,
+}
+
 #[derive(Clone)]
 struct PotentiometerReader {
     gpio: Arc<Gpio>,
@@ -59,24 +181,57 @@ struct PotReading {
 
 struct OscSender {
     socket: UdpSocket,
-    target: SocketAddr,
 }
 
 impl OscSender {
-    fn new(target: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    fn new() -> Result<Self, Box<dyn std::error::Error>> {
         let socket = UdpSocket::bind("0.0.0.0:0")?;
-        let target: SocketAddr = target.parse()?;
-        Ok(Self { socket, target })
+        Ok(Self { socket })
     }
 
-    fn send_value(&self, address: &str, value: f32) -> Result<(), Box<dyn std::error::Error>> {
+    fn send_value(&self, address: &str, value: f32, target: SocketAddr) -> Result<(), Box<dyn std::error::Error>> {
         let msg = OscMessage {
             addr: address.to_string(),
             args: vec![OscType::Float(value)],
         };
         let packet = OscPacket::Message(msg);
         let buf = encoder::encode(&packet)?;
-        self.socket.send_to(&buf, self.target)?;
+        self.socket.send_to(&buf, target)?;
+        Ok(())
+    }
+}
+
+/// AD5680-style 18-bit serial DAC: an SPI bus plus a software-driven SYNC
+/// (chip-select/frame-sync) pin, since the AD5680's timing doesn't line up
+/// with a standard hardware CS.
+struct Dac {
+    spi: Spi,
+    sync: OutputPin,
+}
+
+impl Dac {
+    fn new(
+        bus: Bus,
+        slave_select: SlaveSelect,
+        clock_hz: u32,
+        sync_pin: u8,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let spi = Spi::new(bus, slave_select, clock_hz, SpiMode::Mode1)?;
+        let gpio = Gpio::new()?;
+        let mut sync = gpio.get(sync_pin)?.into_output();
+        sync.set_high(); // idle high between frames
+        Ok(Self { spi, sync })
+    }
+
+    /// Clamp `value` to the DAC's full-scale code and write it as an AD5680
+    /// frame: SYNC low, three bytes `[(v>>14), (v>>6), (v<<2)]`, SYNC high.
+    fn set(&mut self, value: u32) -> Result<(), Box<dyn std::error::Error>> {
+        let value = value.min(DAC_MAX_VALUE);
+        let frame = [(value >> 14) as u8, (value >> 6) as u8, (value << 2) as u8];
+
+        self.sync.set_low();
+        self.spi.write(&frame)?;
+        self.sync.set_high();
         Ok(())
     }
 }
@@ -184,11 +339,246 @@ impl RateLimiter {
         self.current
     }
 
+    /// Rates can be retuned live via the TCP control server
+    fn set_rates(&mut self, max_rate_up: f32, max_rate_down: f32) {
+        self.max_rate_up = max_rate_up;
+        self.max_rate_down = max_rate_down;
+    }
+
     fn get_current(&self) -> f32 {
         self.current
     }
 }
 
+/// Oversampling input filter for the raw charge-time reading.
+/// Takes `oversample_n` samples per reported value and combines them via
+/// `mode`. Samples that hit `CHARGE_MAX_COUNT` (timeout / open circuit) are
+/// excluded so they don't drag a Mean toward the ceiling.
+struct Filter {
+    mode: FilterMode,
+    oversample_n: usize,
+    alpha: f32,
+    iir_value: Option<f32>,
+}
+
+impl Filter {
+    fn new(mode: FilterMode, oversample_n: usize, alpha: f32) -> Self {
+        Self {
+            mode,
+            oversample_n,
+            alpha,
+            iir_value: None,
+        }
+    }
+
+    /// Filter mode and tuning can be retuned live via the TCP control server
+    fn set_params(&mut self, mode: FilterMode, oversample_n: usize, alpha: f32) {
+        self.mode = mode;
+        self.oversample_n = oversample_n;
+        self.alpha = alpha;
+    }
+
+    /// Take `oversample_n` raw charge-time samples from `reader` and combine
+    /// them into one filtered count. Falls back to `CHARGE_MAX_COUNT` if
+    /// every sample in the window timed out. Also returns the raw,
+    /// unfiltered samples (including any that timed out) so callers like
+    /// `FaultDetector` can see real per-`analog_read()` readings instead of
+    /// an aggregate that smooths out intermittent faults.
+    fn sample(&mut self, reader: &PotentiometerReader) -> Result<(u32, Vec<u32>), Box<dyn std::error::Error>> {
+        let n = self.oversample_n.max(1);
+        let mut raw_samples = Vec::with_capacity(n);
+        let mut samples = Vec::with_capacity(n);
+        for _ in 0..n {
+            let raw = reader.analog_read()?;
+            raw_samples.push(raw);
+            if raw < CHARGE_MAX_COUNT {
+                samples.push(raw);
+            }
+        }
+
+        if samples.is_empty() {
+            return Ok((CHARGE_MAX_COUNT, raw_samples));
+        }
+
+        let filtered = match self.mode {
+            FilterMode::Mean => {
+                let sum: u64 = samples.iter().map(|&v| v as u64).sum();
+                (sum / samples.len() as u64) as u32
+            }
+            FilterMode::Median => {
+                samples.sort_unstable();
+                samples[samples.len() / 2]
+            }
+            FilterMode::Iir => {
+                let mut y = self.iir_value.unwrap_or(samples[0] as f32);
+                for &x in &samples {
+                    y += self.alpha * (x as f32 - y);
+                }
+                self.iir_value = Some(y);
+                y.round() as u32
+            }
+        };
+
+        Ok((filtered, raw_samples))
+    }
+}
+
+/// Potentiometer fault state, surfaced in the HTTP/report JSON. `None`
+/// (absent from the JSON) means the readings look sane.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Fault {
+    Open,  // charge time pegged at CHARGE_MAX_COUNT - pin B never goes high
+    Short, // charge time collapsed near zero
+    Noisy, // variance too high for a steady wire
+}
+
+/// Tracks a short ring buffer of recent raw charge-time counts and flags a
+/// fault via mean/variance over the window - a cheaper proxy for fitting a
+/// least-squares quadratic over the same samples. Clears automatically once
+/// the window fills back up with sane, low-variance readings.
+struct FaultDetector {
+    window: std::collections::VecDeque<u32>,
+    window_size: usize,
+}
+
+impl FaultDetector {
+    fn new(window_size: usize) -> Self {
+        Self {
+            window: std::collections::VecDeque::with_capacity(window_size),
+            window_size,
+        }
+    }
+
+    fn push(&mut self, raw: u32) -> Option<Fault> {
+        self.window.push_back(raw);
+        if self.window.len() > self.window_size {
+            self.window.pop_front();
+        }
+
+        if self.window.len() < self.window_size {
+            // Not enough history yet to judge
+            return None;
+        }
+
+        let n = self.window.len() as f64;
+        let mean = self.window.iter().map(|&v| v as f64).sum::<f64>() / n;
+        let variance = self
+            .window
+            .iter()
+            .map(|&v| {
+                let d = v as f64 - mean;
+                d * d
+            })
+            .sum::<f64>()
+            / n;
+
+        if mean >= FAULT_OPEN_THRESHOLD as f64 {
+            Some(Fault::Open)
+        } else if mean <= FAULT_SHORT_THRESHOLD as f64 {
+            Some(Fault::Short)
+        } else if variance >= FAULT_VARIANCE_THRESHOLD {
+            Some(Fault::Noisy)
+        } else {
+            None
+        }
+    }
+}
+
+/// PID controller driving a motorized fader to a setpoint. Differentiates on
+/// the measurement rather than the error to avoid derivative kick when the
+/// setpoint jumps, clamps the integral term for anti-windup, and returns
+/// zero output inside the deadband so the motor doesn't buzz holding position.
+struct Pid {
+    kp: f32,
+    ki: f32,
+    kd: f32,
+    integral: f32,
+    integral_clamp: f32,
+    output_clamp: f32,
+    deadband: f32,
+    prev_measurement: Option<f32>,
+    last_update: std::time::Instant,
+}
+
+impl Pid {
+    fn new(kp: f32, ki: f32, kd: f32, integral_clamp: f32, output_clamp: f32, deadband: f32) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            integral: 0.0,
+            integral_clamp,
+            output_clamp,
+            deadband,
+            prev_measurement: None,
+            last_update: std::time::Instant::now(),
+        }
+    }
+
+    /// Gains and deadband can be retuned live via the TCP control server
+    fn set_tuning(&mut self, kp: f32, ki: f32, kd: f32, deadband: f32) {
+        self.kp = kp;
+        self.ki = ki;
+        self.kd = kd;
+        self.deadband = deadband;
+    }
+
+    /// Compute the next PWM duty (-1.0..=1.0, sign = direction) driving
+    /// `measurement` toward `setpoint`.
+    fn update(&mut self, setpoint: f32, measurement: f32) -> f32 {
+        let now = std::time::Instant::now();
+        let dt = now.duration_since(self.last_update).as_secs_f32().max(0.001);
+        self.last_update = now;
+
+        let error = setpoint - measurement;
+
+        if error.abs() < self.deadband {
+            self.prev_measurement = Some(measurement);
+            return 0.0;
+        }
+
+        self.integral = (self.integral + error * dt).clamp(-self.integral_clamp, self.integral_clamp);
+
+        let derivative = match self.prev_measurement {
+            Some(prev) => -(measurement - prev) / dt,
+            None => 0.0,
+        };
+        self.prev_measurement = Some(measurement);
+
+        let output = self.kp * error + self.ki * self.integral + self.kd * derivative;
+        output.clamp(-self.output_clamp, self.output_clamp)
+    }
+}
+
+/// Drives an H-bridge motor from a signed PWM duty: sign selects direction
+/// (forward/reverse pin), magnitude is the duty cycle on that pin.
+struct MotorDriver {
+    fwd: OutputPin,
+    rev: OutputPin,
+}
+
+impl MotorDriver {
+    fn new(fwd_pin: u8, rev_pin: u8) -> Result<Self, Box<dyn std::error::Error>> {
+        let gpio = Gpio::new()?;
+        let fwd = gpio.get(fwd_pin)?.into_output();
+        let rev = gpio.get(rev_pin)?.into_output();
+        Ok(Self { fwd, rev })
+    }
+
+    fn drive(&mut self, duty: f32) -> Result<(), Box<dyn std::error::Error>> {
+        let duty = duty.clamp(-1.0, 1.0) as f64;
+        if duty >= 0.0 {
+            self.rev.clear_pwm()?;
+            self.fwd.set_pwm_frequency(MOTOR_PWM_FREQUENCY_HZ, duty)?;
+        } else {
+            self.fwd.clear_pwm()?;
+            self.rev.set_pwm_frequency(MOTOR_PWM_FREQUENCY_HZ, -duty)?;
+        }
+        Ok(())
+    }
+}
+
 impl PotentiometerReader {
     fn new() -> Result<Self, Box<dyn std::error::Error>> {
         let gpio = Gpio::new()?;
@@ -212,7 +602,7 @@ impl PotentiometerReader {
 
         let mut count: u32 = 0;
         // Timeout after reasonable count to prevent infinite loop
-        let max_count: u32 = 1_000_000;
+        let max_count: u32 = CHARGE_MAX_COUNT;
 
         while pin_b.is_low() && count < max_count {
             count += 1;
@@ -230,6 +620,7 @@ impl PotentiometerReader {
 // Shared state for the HTTP server
 struct AppState {
     last_reading: Arc<Mutex<u32>>,
+    report_tx: broadcast::Sender<Report>,
 }
 
 async fn get_potentiometer(State(state): State<Arc<AppState>>) -> Json<PotReading> {
@@ -242,6 +633,280 @@ async fn get_potentiometer(State(state): State<Arc<AppState>>) -> Json<PotReadin
     Json(PotReading { value, timestamp })
 }
 
+/// Push a JSON report per sample over Server-Sent Events, so a desk app can
+/// plot fader motion smoothly instead of aliasing a slow poll loop.
+async fn stream_potentiometer(
+    State(state): State<Arc<AppState>>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = BroadcastStream::new(state.report_tx.subscribe()).filter_map(|sample| {
+        let sample = sample.ok()?;
+        let json = serde_json::to_string(&sample).ok()?;
+        Some(Ok(Event::default().data(json)))
+    });
+
+    Sse::new(stream).keep_alive(axum::response::sse::KeepAlive::default())
+}
+
+fn ok_json() -> String {
+    serde_json::json!({"ok": true}).to_string()
+}
+
+fn error_json(msg: &str) -> String {
+    serde_json::json!({"ok": false, "error": msg}).to_string()
+}
+
+/// Parse and apply one control command, returning a single JSON line to send back.
+///
+/// Supported commands:
+///   osc target <host:port>   retarget OSC output
+///   curve linear|log|exp     switch the volume curve
+///   rate up|down <units/s>   retune the rate limiter slew rates
+///   cal <min> <max>          retune potentiometer calibration
+///   interval <ms>            retune the sample period (10-60000ms)
+///   filter mode mean|median|iir   switch the oversampling filter mode
+///   filter n <count>         retune the number of samples combined per reading
+///   filter alpha <value>     retune the IIR filter's smoothing factor
+///   fader <value>            set the motorized-fader position setpoint (0.0-1.0)
+///   pid kp|ki|kd <value>     retune the fader servo's PID gains
+///   pid deadband <value>     retune the fader servo's deadband
+///   report                   return the latest raw/linear/target/actual/db reading
+///
+/// `stream on|off` (continuous per-sample reports on a connection) is handled
+/// by `handle_control_connection` before a line reaches this function.
+fn handle_command(line: &str, config: &Mutex<Config>, report: &Mutex<Report>) -> String {
+    let mut parts = line.split_whitespace();
+    let cmd = parts.next().unwrap_or("");
+
+    match cmd {
+        "osc" => match parts.next() {
+            Some("target") => match parts.next().and_then(|s| s.parse::<SocketAddr>().ok()) {
+                Some(addr) => {
+                    config.lock().unwrap().osc_target = addr;
+                    ok_json()
+                }
+                None => error_json("usage: osc target <host:port>"),
+            },
+            _ => error_json("usage: osc target <host:port>"),
+        },
+
+        "curve" => match parts.next() {
+            Some("linear") => {
+                config.lock().unwrap().volume_curve = VolumeCurve::Linear;
+                ok_json()
+            }
+            Some("log") => {
+                config.lock().unwrap().volume_curve = VolumeCurve::Logarithmic;
+                ok_json()
+            }
+            Some("exp") | Some("exponential") => {
+                config.lock().unwrap().volume_curve = VolumeCurve::Exponential;
+                ok_json()
+            }
+            _ => error_json("usage: curve linear|log|exponential"),
+        },
+
+        "rate" => {
+            let direction = parts.next();
+            let value = parts.next().and_then(|s| s.parse::<f32>().ok());
+            match (direction, value) {
+                (Some("up"), Some(v)) if v >= 0.0 => {
+                    config.lock().unwrap().max_rate_up = v;
+                    ok_json()
+                }
+                (Some("down"), Some(v)) if v >= 0.0 => {
+                    config.lock().unwrap().max_rate_down = v;
+                    ok_json()
+                }
+                _ => error_json("usage: rate up|down <units/sec>, units/sec >= 0"),
+            }
+        }
+
+        "cal" => {
+            let min = parts.next().and_then(|s| s.parse::<u32>().ok());
+            let max = parts.next().and_then(|s| s.parse::<u32>().ok());
+            match (min, max) {
+                (Some(min), Some(max)) if min < max => {
+                    let mut config = config.lock().unwrap();
+                    config.pot_min = min;
+                    config.pot_max = max;
+                    ok_json()
+                }
+                _ => error_json("usage: cal <min> <max>, min < max"),
+            }
+        }
+
+        "interval" => match parts.next().and_then(|s| s.parse::<u64>().ok()) {
+            Some(ms) => {
+                config.lock().unwrap().sample_interval_ms =
+                    ms.clamp(MIN_SAMPLE_INTERVAL_MS, MAX_SAMPLE_INTERVAL_MS);
+                ok_json()
+            }
+            None => error_json("usage: interval <ms>"),
+        },
+
+        "filter" => match parts.next() {
+            Some("mode") => match parts.next() {
+                Some("mean") => {
+                    config.lock().unwrap().filter_mode = FilterMode::Mean;
+                    ok_json()
+                }
+                Some("median") => {
+                    config.lock().unwrap().filter_mode = FilterMode::Median;
+                    ok_json()
+                }
+                Some("iir") => {
+                    config.lock().unwrap().filter_mode = FilterMode::Iir;
+                    ok_json()
+                }
+                _ => error_json("usage: filter mode mean|median|iir"),
+            },
+            Some("n") => match parts.next().and_then(|s| s.parse::<usize>().ok()) {
+                Some(n) => {
+                    config.lock().unwrap().filter_oversample_n =
+                        n.clamp(MIN_FILTER_OVERSAMPLE_N, MAX_FILTER_OVERSAMPLE_N);
+                    ok_json()
+                }
+                None => error_json("usage: filter n <count>"),
+            },
+            Some("alpha") => match parts.next().and_then(|s| s.parse::<f32>().ok()) {
+                Some(alpha) if alpha > 0.0 && alpha <= 1.0 => {
+                    config.lock().unwrap().filter_alpha = alpha;
+                    ok_json()
+                }
+                _ => error_json("usage: filter alpha <value>, 0 < value <= 1"),
+            },
+            _ => error_json("usage: filter mode|n|alpha ..."),
+        },
+
+        "fader" => match parts.next().and_then(|s| s.parse::<f32>().ok()) {
+            Some(value) => {
+                config.lock().unwrap().fader_setpoint = value.clamp(0.0, 1.0);
+                ok_json()
+            }
+            None => error_json("usage: fader <value>, 0.0-1.0"),
+        },
+
+        "pid" => match parts.next() {
+            Some("kp") => match parts.next().and_then(|s| s.parse::<f32>().ok()) {
+                Some(v) => {
+                    config.lock().unwrap().pid_kp = v;
+                    ok_json()
+                }
+                None => error_json("usage: pid kp <value>"),
+            },
+            Some("ki") => match parts.next().and_then(|s| s.parse::<f32>().ok()) {
+                Some(v) => {
+                    config.lock().unwrap().pid_ki = v;
+                    ok_json()
+                }
+                None => error_json("usage: pid ki <value>"),
+            },
+            Some("kd") => match parts.next().and_then(|s| s.parse::<f32>().ok()) {
+                Some(v) => {
+                    config.lock().unwrap().pid_kd = v;
+                    ok_json()
+                }
+                None => error_json("usage: pid kd <value>"),
+            },
+            Some("deadband") => match parts.next().and_then(|s| s.parse::<f32>().ok()) {
+                Some(v) if v >= 0.0 => {
+                    config.lock().unwrap().pid_deadband = v;
+                    ok_json()
+                }
+                _ => error_json("usage: pid deadband <value>, value >= 0"),
+            },
+            _ => error_json("usage: pid kp|ki|kd|deadband <value>"),
+        },
+
+        "report" => serde_json::to_string(&*report.lock().unwrap())
+            .unwrap_or_else(|e| error_json(&format!("failed to serialize report: {}", e))),
+
+        "" => error_json("empty command"),
+
+        other => error_json(&format!("unknown command: {}", other)),
+    }
+}
+
+async fn handle_control_connection(
+    socket: TcpStream,
+    config: Arc<Mutex<Config>>,
+    report: Arc<Mutex<Report>>,
+    report_tx: broadcast::Sender<Report>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (reader, mut writer) = socket.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    let mut report_rx = report_tx.subscribe();
+    let mut streaming = false;
+
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                let Some(line) = line? else { break };
+                let response = match line.trim() {
+                    "stream on" => { streaming = true; ok_json() }
+                    "stream off" => { streaming = false; ok_json() }
+                    other => handle_command(other, &config, &report),
+                };
+                writer.write_all(response.as_bytes()).await?;
+                writer.write_all(b"\n").await?;
+            }
+            sample = report_rx.recv(), if streaming => {
+                match sample {
+                    Ok(sample) => {
+                        let line = serde_json::to_string(&sample)
+                            .unwrap_or_else(|e| error_json(&format!("failed to serialize sample: {}", e)));
+                        writer.write_all(line.as_bytes()).await?;
+                        writer.write_all(b"\n").await?;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn run_tcp_control_server(
+    config: Arc<Mutex<Config>>,
+    report: Arc<Mutex<Report>>,
+    report_tx: broadcast::Sender<Report>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let listener = tokio::net::TcpListener::bind(TCP_CONTROL_ADDR).await?;
+    println!("TCP control server listening on {}", TCP_CONTROL_ADDR);
+
+    loop {
+        let (socket, _) = listener.accept().await?;
+        let config = Arc::clone(&config);
+        let report = Arc::clone(&report);
+        let report_tx = report_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_control_connection(socket, config, report, report_tx).await {
+                eprintln!("TCP control connection error: {}", e);
+            }
+        });
+    }
+}
+
+/// Listen for incoming OSC float messages and use each one as the fader
+/// servo's position setpoint. Any OSC address is accepted; only the first
+/// argument is used, and only when it is a float.
+async fn run_osc_listener(bind_addr: &str, config: Arc<Mutex<Config>>) -> Result<(), Box<dyn std::error::Error>> {
+    let socket = tokio::net::UdpSocket::bind(bind_addr).await?;
+    println!("OSC listener bound to {} (sets fader setpoint)", bind_addr);
+
+    let mut buf = [0u8; 1024];
+    loop {
+        let (len, _src) = socket.recv_from(&mut buf).await?;
+        if let Ok((_, OscPacket::Message(msg))) = decoder::decode_udp(&buf[..len]) {
+            if let Some(OscType::Float(value)) = msg.args.first() {
+                config.lock().unwrap().fader_setpoint = value.clamp(0.0, 1.0);
+            }
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Starting GPIO Potentiometer Reader (Rust)");
@@ -249,7 +914,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Initialize OSC sender
     let osc_sender = if OSC_ENABLED {
-        match OscSender::new(OSC_TARGET) {
+        match OscSender::new() {
             Ok(sender) => {
                 println!("OSC enabled: sending to {} on address {}", OSC_TARGET, OSC_ADDRESS);
                 Some(sender)
@@ -265,6 +930,46 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         None
     };
 
+    // Initialize analog (DAC) output
+    let mut dac = if DAC_ENABLED {
+        match Dac::new(DAC_SPI_BUS, DAC_SPI_SLAVE_SELECT, DAC_SPI_CLOCK_HZ, DAC_SYNC_PIN) {
+            Ok(dac) => {
+                println!("DAC enabled: SPI bus {:?}, SYNC pin {}", DAC_SPI_BUS, DAC_SYNC_PIN);
+                Some(dac)
+            }
+            Err(e) => {
+                eprintln!("Warning: Could not initialize DAC: {}", e);
+                println!("Continuing without DAC support");
+                None
+            }
+        }
+    } else {
+        println!("DAC disabled in configuration");
+        None
+    };
+
+    // Initialize motorized-fader position servo
+    let mut motor = if FADER_SERVO_ENABLED {
+        match MotorDriver::new(MOTOR_PIN_FWD, MOTOR_PIN_REV) {
+            Ok(motor) => {
+                println!(
+                    "Fader servo enabled: motor pins fwd={}, rev={}",
+                    MOTOR_PIN_FWD, MOTOR_PIN_REV
+                );
+                Some(motor)
+            }
+            Err(e) => {
+                eprintln!("Warning: Could not initialize motor driver: {}", e);
+                println!("Continuing without fader servo support");
+                None
+            }
+        }
+    } else {
+        println!("Fader servo disabled in configuration");
+        None
+    };
+    let mut pid = Pid::new(PID_KP, PID_KI, PID_KD, PID_INTEGRAL_CLAMP, PID_OUTPUT_CLAMP, PID_DEADBAND);
+
     let reader = PotentiometerReader::new()?;
     let last_reading = Arc::new(Mutex::new(0u32));
     let last_reading_clone = Arc::clone(&last_reading);
@@ -284,26 +989,116 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         None
     };
 
+    // Runtime-tunable config, shared with the TCP control server
+    let config = Arc::new(Mutex::new(Config {
+        osc_target: OSC_TARGET.parse()?,
+        volume_curve: VOLUME_CURVE,
+        pot_min: POT_MIN,
+        pot_max: POT_MAX,
+        max_rate_up: MAX_RATE_UP,
+        max_rate_down: MAX_RATE_DOWN,
+        sample_interval_ms: SAMPLE_INTERVAL_MS,
+        filter_mode: FILTER_MODE,
+        filter_oversample_n: FILTER_OVERSAMPLE_N,
+        filter_alpha: FILTER_ALPHA,
+        fader_setpoint: 0.0,
+        pid_kp: PID_KP,
+        pid_ki: PID_KI,
+        pid_kd: PID_KD,
+        pid_deadband: PID_DEADBAND,
+    }));
+    let config_clone = Arc::clone(&config);
+
+    // Latest processed reading, served by the TCP control server's `report` command
+    let report = Arc::new(Mutex::new(Report::default()));
+    let report_clone = Arc::clone(&report);
+
+    // Broadcasts one Report per sample to `/stream` subscribers and TCP
+    // connections in `stream on` mode
+    let (report_tx, _) = broadcast::channel(REPORT_CHANNEL_CAPACITY);
+    let report_tx_clone = report_tx.clone();
+    let report_tx_http = report_tx.clone();
+
+    // TCP control server
+    let config_for_osc_listener = Arc::clone(&config);
+    tokio::spawn(async move {
+        if let Err(e) = run_tcp_control_server(config, report, report_tx).await {
+            eprintln!("TCP control server error: {}", e);
+        }
+    });
+
+    // OSC listener for the fader servo's position setpoint
+    if FADER_SERVO_ENABLED {
+        tokio::spawn(async move {
+            if let Err(e) = run_osc_listener(OSC_LISTEN_ADDR, config_for_osc_listener).await {
+                eprintln!("OSC listener error: {}", e);
+            }
+        });
+    }
+
     // Background task to continuously read the potentiometer
+    let mut filter = Filter::new(FILTER_MODE, FILTER_OVERSAMPLE_N, FILTER_ALPHA);
+    let mut fault_detector = FaultDetector::new(FAULT_WINDOW_SIZE);
     tokio::spawn(async move {
         loop {
-            match reader.analog_read() {
-                Ok(value) => {
+            let current_config = config_clone.lock().unwrap().clone();
+            filter.set_params(
+                current_config.filter_mode,
+                current_config.filter_oversample_n,
+                current_config.filter_alpha,
+            );
+
+            // `Filter::sample` blocks on `reader.analog_read()` (a 4ms sleep
+            // plus a busy-loop) up to `oversample_n` times back-to-back; run
+            // it on a blocking-pool thread so a large oversample count can't
+            // stall this task's async runtime worker and starve everything
+            // else scheduled on it (the TCP/HTTP/OSC listeners included)
+            let reader_for_sample = reader.clone();
+            let (filter_back, sample_result) = tokio::task::spawn_blocking(move || {
+                let result = filter.sample(&reader_for_sample);
+                (filter, result)
+            })
+            .await
+            .expect("potentiometer sampling task panicked");
+            filter = filter_back;
+
+            match sample_result {
+                Ok((value, raw_samples)) => {
+                    // Feed every raw charge-time sample in this window to
+                    // the fault detector, not just the filtered aggregate -
+                    // an intermittent short/open affecting only some of the
+                    // N sub-samples would otherwise get smoothed away
+                    let mut fault = None;
+                    for raw in raw_samples {
+                        fault = fault_detector.push(raw);
+                    }
+
                     // Step 1: Normalize raw reading to 0.0-1.0 (linear)
-                    let linear = normalize_value(value, POT_MIN, POT_MAX);
+                    let linear = normalize_value(value, current_config.pot_min, current_config.pot_max);
 
                     // Step 2: Apply volume curve
-                    let target = apply_volume_curve(linear, VOLUME_CURVE, DB_MIN, DB_MAX);
+                    let target = apply_volume_curve(linear, current_config.volume_curve, DB_MIN, DB_MAX);
 
                     // Step 3: Apply rate limiting if enabled
                     let actual = if let Some(ref mut limiter) = rate_limiter {
-                        let limited = limiter.update(target);
-                        let db = linear_to_db(limited, DB_MIN, DB_MAX);
-                        println!(
-                            "Pot: raw={}, linear={:.3}, target={:.3}, actual={:.3} ({:.1} dB) [rate limited]",
-                            value, linear, target, limited, db
-                        );
-                        limited
+                        limiter.set_rates(current_config.max_rate_up, current_config.max_rate_down);
+                        if fault.is_some() {
+                            // Freeze while faulted: don't let `actual` slew
+                            // toward a target derived from a garbage
+                            // reading, but still tick the limiter's clock so
+                            // a long outage doesn't unlock one huge step the
+                            // instant the fault clears
+                            let frozen = limiter.get_current();
+                            limiter.update(frozen)
+                        } else {
+                            let limited = limiter.update(target);
+                            let db = linear_to_db(limited, DB_MIN, DB_MAX);
+                            println!(
+                                "Pot: raw={}, linear={:.3}, target={:.3}, actual={:.3} ({:.1} dB) [rate limited]",
+                                value, linear, target, limited, db
+                            );
+                            limited
+                        }
                     } else {
                         let db = linear_to_db(target, DB_MIN, DB_MAX);
                         println!(
@@ -315,10 +1110,74 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                     *last_reading_clone.lock().unwrap() = value;
 
-                    // Send OSC message with final processed value
-                    if let Some(ref sender) = osc_sender {
-                        if let Err(e) = sender.send_value(OSC_ADDRESS, actual) {
-                            eprintln!("OSC send error: {}", e);
+                    // Fader servo: drive the motor so the physical fader
+                    // tracks the setpoint, using the filtered pot reading
+                    // (not the curve-shaped output) as the process variable
+                    let duty = if let Some(ref mut motor) = motor {
+                        pid.set_tuning(
+                            current_config.pid_kp,
+                            current_config.pid_ki,
+                            current_config.pid_kd,
+                            current_config.pid_deadband,
+                        );
+                        let duty = if fault.is_some() {
+                            // Keep feeding the PID its own measurement as
+                            // the setpoint so error stays zero - this ticks
+                            // `last_update`/`prev_measurement` forward
+                            // without touching the integral, so a long
+                            // outage doesn't dump a huge dt into it (or
+                            // derivative-kick off a stale measurement) the
+                            // instant the fault clears
+                            pid.update(linear, linear);
+                            0.0
+                        } else {
+                            pid.update(current_config.fader_setpoint, linear)
+                        };
+                        if let Err(e) = motor.drive(duty) {
+                            eprintln!("Motor drive error: {}", e);
+                        }
+                        duty
+                    } else {
+                        0.0
+                    };
+
+                    let db = linear_to_db(actual, DB_MIN, DB_MAX);
+                    let timestamp = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap()
+                        .as_millis() as u64;
+                    let sample = Report {
+                        raw: value,
+                        linear,
+                        target,
+                        actual,
+                        db,
+                        timestamp,
+                        fault,
+                        setpoint: current_config.fader_setpoint,
+                        measured: linear,
+                        duty,
+                    };
+                    *report_clone.lock().unwrap() = sample.clone();
+                    // Ignore send errors: just means no one is subscribed right now
+                    let _ = report_tx_clone.send(sample);
+
+                    // A fault means the reading can't be trusted - don't let
+                    // a garbage value slam the OSC target
+                    if let Some(fault) = fault {
+                        eprintln!("Potentiometer fault detected: {:?} - suppressing OSC output", fault);
+                    } else {
+                        if let Some(ref sender) = osc_sender {
+                            if let Err(e) = sender.send_value(OSC_ADDRESS, actual, current_config.osc_target) {
+                                eprintln!("OSC send error: {}", e);
+                            }
+                        }
+
+                        if let Some(ref mut dac) = dac {
+                            let code = (actual.clamp(0.0, 1.0) * DAC_MAX_VALUE as f32).round() as u32;
+                            if let Err(e) = dac.set(code) {
+                                eprintln!("DAC write error: {}", e);
+                            }
                         }
                     }
                 }
@@ -326,15 +1185,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     eprintln!("Error reading potentiometer: {}", e);
                 }
             }
-            tokio::time::sleep(Duration::from_secs(1)).await;
+            tokio::time::sleep(Duration::from_millis(current_config.sample_interval_ms)).await;
         }
     });
 
     // HTTP server
-    let app_state = Arc::new(AppState { last_reading });
+    let app_state = Arc::new(AppState {
+        last_reading,
+        report_tx: report_tx_http,
+    });
 
     let app = Router::new()
         .route("/potentiometer", get(get_potentiometer))
+        .route("/stream", get(stream_potentiometer))
         .route("/health", get(|| async { "OK" }))
         .with_state(app_state);
 
@@ -342,6 +1205,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("HTTP server listening on http://0.0.0.0:3000");
     println!("Endpoints:");
     println!("  GET /potentiometer - Get current potentiometer reading");
+    println!("  GET /stream        - SSE stream of per-sample reports");
     println!("  GET /health        - Health check");
 
     axum::serve(listener, app).await?;